@@ -11,10 +11,24 @@ pub enum NeuralPathwayError {
     InvalidAgent,
     #[error("Pathway Already Exists")]
     PathwayAlreadyExists,
+    #[error("Invalid Reply Target")]
+    InvalidReplyTarget,
+    #[error("Invalid VAA")]
+    InvalidVaa,
+    #[error("Guardian Set Mismatch")]
+    GuardianSetMismatch,
+    #[error("Insufficient Guardian Signatures")]
+    InsufficientSignatures,
+    #[error("Unauthorized Emitter")]
+    UnauthorizedEmitter,
+    #[error("VAA Already Executed")]
+    VaaAlreadyExecuted,
+    #[error("Unauthorized Guardian Set Admin")]
+    UnauthorizedGuardianSetAdmin,
 }
 
 impl From<NeuralPathwayError> for ProgramError {
     fn from(e: NeuralPathwayError) -> Self {
         ProgramError::Custom(e as u32)
     }
-} 
\ No newline at end of file
+}
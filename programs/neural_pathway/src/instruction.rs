@@ -0,0 +1,89 @@
+use borsh::BorshDeserialize;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::NeuralPathwayError;
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub enum NeuralPathwayInstruction {
+    /// Create a pathway from the signing source agent to `target`, at the
+    /// PDA derived from `(source_agent, target)`.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` source agent
+    /// 1. `[writable]` pathway PDA, uninitialized
+    /// 2. `[]` system program
+    CreatePathway { target: Pubkey },
+
+    /// Record a successful use of a pathway, reinforcing its strength.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` source or target agent of the pathway
+    /// 1. `[writable]` pathway PDA
+    RecordSuccess,
+
+    /// Record a failed use of a pathway, weakening its strength.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` source or target agent of the pathway
+    /// 1. `[writable]` pathway PDA
+    RecordFailure,
+
+    /// Apply time-based decay to a pathway without recording an outcome.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` source or target agent of the pathway
+    /// 1. `[writable]` pathway PDA
+    DecayStrength,
+
+    /// Post a message attached to an existing pathway, signed by one of its
+    /// two agents.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` author (source or target agent of the pathway)
+    /// 1. `[writable]` pathway PDA
+    /// 2. `[writable]` message PDA, uninitialized
+    /// 3. `[]` system program
+    /// 4. `[]` parent message PDA, required iff `reply_to.is_some()`
+    PostMessage {
+        reply_to: Option<Pubkey>,
+        body: String,
+    },
+
+    /// Verify a Wormhole VAA asserting a pathway on a foreign chain and
+    /// mirror it locally, creating or reinforcing the corresponding pathway.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` payer
+    /// 1. `[]` guardian set account
+    /// 2. `[writable]` local pathway PDA for the mirrored agent pair
+    /// 3. `[writable]` replay-guard PDA for this VAA's (emitter, sequence)
+    /// 4. `[]` system program
+    MirrorPathwayViaVaa { vaa: Vec<u8> },
+
+    /// Create guardian set `index`, or rotate to it from `index - 1`.
+    ///
+    /// For `index == 0` this bootstraps trust: the signer becomes the
+    /// set's `admin`. For `index > 0` the signer must be the `admin` of
+    /// guardian set `index - 1`, and becomes the `admin` of the new set.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` admin / payer
+    /// 1. `[writable]` new guardian set PDA for `index`, uninitialized
+    /// 2. `[]` previous guardian set PDA for `index - 1`, required iff
+    ///    `index > 0`
+    /// 3. `[]` system program
+    RotateGuardianSet {
+        index: u32,
+        guardians: Vec<[u8; 20]>,
+        expiration_time: i64,
+        allowed_emitter_chain: u16,
+        allowed_emitter_address: [u8; 32],
+    },
+}
+
+impl NeuralPathwayInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        Self::try_from_slice(input).map_err(|_| NeuralPathwayError::InvalidInstruction.into())
+    }
+}
@@ -0,0 +1,741 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+use solana_program::sysvar::clock::Clock;
+use solana_program::sysvar::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::NeuralPathwayError;
+use crate::instruction::NeuralPathwayInstruction;
+use crate::state::{GuardianSet, NeuralPathway, PathwayMessage, ProcessedVaa};
+use crate::wormhole::Vaa;
+
+pub struct Processor;
+
+/// Creates `target_info` as a `space`-byte account owned by `owner`, signed
+/// for by `target_seeds`.
+///
+/// Uses plain `create_account` in the common case, but tolerates
+/// `target_info` having been pre-funded with lamports (and left otherwise
+/// uninitialized) before this instruction ran: anyone can send lamports to a
+/// PDA ahead of its legitimate creation transaction, and `create_account`
+/// refuses to act on a non-zero-lamport account, which would otherwise
+/// permanently block creation. In that case, top the account up to
+/// rent-exemption and bring it under this program's ownership with
+/// Allocate + Assign instead, which only require an empty owner/data, not
+/// zero lamports.
+fn create_pda_account<'a>(
+    payer_info: &AccountInfo<'a>,
+    target_info: &AccountInfo<'a>,
+    target_seeds: &[&[u8]],
+    space: usize,
+    owner: &Pubkey,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    let required_lamports = rent.minimum_balance(space);
+
+    if target_info.lamports() == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                target_info.key,
+                required_lamports,
+                space as u64,
+                owner,
+            ),
+            &[
+                payer_info.clone(),
+                target_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[target_seeds],
+        );
+    }
+
+    let shortfall = required_lamports.saturating_sub(target_info.lamports());
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, target_info.key, shortfall),
+            &[
+                payer_info.clone(),
+                target_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(target_info.key, space as u64),
+        &[target_info.clone(), system_program_info.clone()],
+        &[target_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(target_info.key, owner),
+        &[target_info.clone(), system_program_info.clone()],
+        &[target_seeds],
+    )
+}
+
+impl Processor {
+    pub fn process(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        instruction_data: &[u8],
+    ) -> ProgramResult {
+        let instruction = NeuralPathwayInstruction::unpack(instruction_data)?;
+
+        match instruction {
+            NeuralPathwayInstruction::CreatePathway { target } => {
+                Self::process_create_pathway(program_id, accounts, target)
+            }
+            NeuralPathwayInstruction::RecordSuccess => {
+                Self::process_record_outcome(program_id, accounts, true)
+            }
+            NeuralPathwayInstruction::RecordFailure => {
+                Self::process_record_outcome(program_id, accounts, false)
+            }
+            NeuralPathwayInstruction::DecayStrength => {
+                Self::process_decay_strength(program_id, accounts)
+            }
+            NeuralPathwayInstruction::PostMessage { reply_to, body } => {
+                Self::process_post_message(program_id, accounts, reply_to, body)
+            }
+            NeuralPathwayInstruction::MirrorPathwayViaVaa { vaa } => {
+                Self::process_mirror_via_vaa(program_id, accounts, &vaa)
+            }
+            NeuralPathwayInstruction::RotateGuardianSet {
+                index,
+                guardians,
+                expiration_time,
+                allowed_emitter_chain,
+                allowed_emitter_address,
+            } => Self::process_rotate_guardian_set(
+                program_id,
+                accounts,
+                index,
+                guardians,
+                expiration_time,
+                allowed_emitter_chain,
+                allowed_emitter_address,
+            ),
+        }
+    }
+
+    fn process_create_pathway(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        target: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_agent_info = next_account_info(account_info_iter)?;
+        let pathway_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        if !source_agent_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let (pathway_pda, bump_seed) = Pubkey::find_program_address(
+            &[b"pathway", source_agent_info.key.as_ref(), target.as_ref()],
+            program_id,
+        );
+        if pathway_pda != *pathway_info.key {
+            return Err(NeuralPathwayError::InvalidAgent.into());
+        }
+        if !pathway_info.data_is_empty() {
+            return Err(NeuralPathwayError::PathwayAlreadyExists.into());
+        }
+
+        create_pda_account(
+            source_agent_info,
+            pathway_info,
+            &[
+                b"pathway",
+                source_agent_info.key.as_ref(),
+                target.as_ref(),
+                &[bump_seed],
+            ],
+            NeuralPathway::LEN,
+            program_id,
+            system_program_info,
+        )?;
+
+        let clock = Clock::get()?;
+        let pathway = NeuralPathway::new(*source_agent_info.key, target, clock.unix_timestamp);
+        pathway.serialize(&mut &mut pathway_info.data.borrow_mut()[..])?;
+
+        Ok(())
+    }
+
+    fn process_record_outcome(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        success: bool,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let agent_info = next_account_info(account_info_iter)?;
+        let pathway_info = next_account_info(account_info_iter)?;
+
+        if pathway_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let mut pathway = NeuralPathway::try_from_slice(&pathway_info.data.borrow())?;
+        Self::check_is_pathway_agent(agent_info, &pathway)?;
+
+        let clock = Clock::get()?;
+        pathway.reinforce(success, clock.unix_timestamp);
+        pathway.serialize(&mut &mut pathway_info.data.borrow_mut()[..])?;
+
+        Ok(())
+    }
+
+    fn process_decay_strength(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let agent_info = next_account_info(account_info_iter)?;
+        let pathway_info = next_account_info(account_info_iter)?;
+
+        if pathway_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let mut pathway = NeuralPathway::try_from_slice(&pathway_info.data.borrow())?;
+        Self::check_is_pathway_agent(agent_info, &pathway)?;
+
+        let clock = Clock::get()?;
+        pathway.decay_strength(clock.unix_timestamp);
+        pathway.serialize(&mut &mut pathway_info.data.borrow_mut()[..])?;
+
+        Ok(())
+    }
+
+    /// Requires `agent_info` to be a signer and one of the pathway's two
+    /// agents, so only participants in a pathway can record its outcomes.
+    fn check_is_pathway_agent(agent_info: &AccountInfo, pathway: &NeuralPathway) -> ProgramResult {
+        if !agent_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if *agent_info.key != pathway.source_agent && *agent_info.key != pathway.target_agent {
+            return Err(NeuralPathwayError::InvalidAgent.into());
+        }
+        Ok(())
+    }
+
+    fn process_post_message(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        reply_to: Option<Pubkey>,
+        body: String,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let author_info = next_account_info(account_info_iter)?;
+        let pathway_info = next_account_info(account_info_iter)?;
+        let message_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        if !author_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if pathway_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        let mut pathway = NeuralPathway::try_from_slice(&pathway_info.data.borrow())?;
+        if *author_info.key != pathway.source_agent && *author_info.key != pathway.target_agent {
+            return Err(NeuralPathwayError::InvalidAgent.into());
+        }
+
+        if let Some(parent) = reply_to {
+            let parent_info = next_account_info(account_info_iter)?;
+            if parent_info.key != &parent {
+                return Err(NeuralPathwayError::InvalidReplyTarget.into());
+            }
+            if parent_info.owner != program_id {
+                return Err(NeuralPathwayError::InvalidReplyTarget.into());
+            }
+            let parent_message =
+                PathwayMessage::deserialize(&mut &parent_info.data.borrow()[..])?;
+            if parent_message.pathway_id != *pathway_info.key {
+                return Err(NeuralPathwayError::InvalidReplyTarget.into());
+            }
+        }
+
+        let index = pathway.message_count;
+        let (message_pda, bump_seed) = Pubkey::find_program_address(
+            &[b"message", pathway_info.key.as_ref(), &index.to_le_bytes()],
+            program_id,
+        );
+        if message_pda != *message_info.key {
+            return Err(NeuralPathwayError::InvalidAgent.into());
+        }
+        if !message_info.data_is_empty() {
+            return Err(NeuralPathwayError::PathwayAlreadyExists.into());
+        }
+
+        let space = PathwayMessage::len_for(reply_to.as_ref(), body.len());
+        create_pda_account(
+            author_info,
+            message_info,
+            &[
+                b"message",
+                pathway_info.key.as_ref(),
+                &index.to_le_bytes(),
+                &[bump_seed],
+            ],
+            space,
+            program_id,
+            system_program_info,
+        )?;
+
+        let clock = Clock::get()?;
+        let message = PathwayMessage {
+            pathway_id: *pathway_info.key,
+            author: *author_info.key,
+            posted_at: clock.unix_timestamp,
+            reply_to,
+            body,
+        };
+        message.serialize(&mut &mut message_info.data.borrow_mut()[..])?;
+
+        pathway.message_count = pathway.message_count.saturating_add(1);
+        pathway.serialize(&mut &mut pathway_info.data.borrow_mut()[..])?;
+
+        Ok(())
+    }
+
+    fn process_mirror_via_vaa(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        vaa_bytes: &[u8],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payer_info = next_account_info(account_info_iter)?;
+        let guardian_set_info = next_account_info(account_info_iter)?;
+        let pathway_info = next_account_info(account_info_iter)?;
+        let replay_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        if !payer_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if guardian_set_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        let vaa = Vaa::parse(vaa_bytes).map_err(ProgramError::from)?;
+
+        let (guardian_set_pda, _) = Pubkey::find_program_address(
+            &[b"guardian_set", &vaa.guardian_set_index.to_le_bytes()],
+            program_id,
+        );
+        if guardian_set_pda != *guardian_set_info.key {
+            return Err(NeuralPathwayError::GuardianSetMismatch.into());
+        }
+
+        let guardian_set = GuardianSet::try_from_slice(&guardian_set_info.data.borrow())?;
+
+        if vaa.guardian_set_index != guardian_set.index {
+            return Err(NeuralPathwayError::GuardianSetMismatch.into());
+        }
+
+        let clock = Clock::get()?;
+        if clock.unix_timestamp >= guardian_set.expiration_time {
+            return Err(NeuralPathwayError::GuardianSetMismatch.into());
+        }
+
+        vaa.verify_quorum(&guardian_set.guardians)
+            .map_err(ProgramError::from)?;
+
+        if vaa.body.emitter_chain != guardian_set.allowed_emitter_chain
+            || vaa.body.emitter_address != guardian_set.allowed_emitter_address
+        {
+            return Err(NeuralPathwayError::UnauthorizedEmitter.into());
+        }
+
+        if vaa.body.payload.len() != 64 {
+            return Err(NeuralPathwayError::InvalidVaa.into());
+        }
+        let source_agent = Pubkey::try_from(&vaa.body.payload[0..32])
+            .map_err(|_| NeuralPathwayError::InvalidVaa)?;
+        let target_agent = Pubkey::try_from(&vaa.body.payload[32..64])
+            .map_err(|_| NeuralPathwayError::InvalidVaa)?;
+
+        let (replay_pda, replay_bump) = Pubkey::find_program_address(
+            &[
+                b"vaa",
+                &vaa.body.emitter_address,
+                &vaa.body.sequence.to_le_bytes(),
+            ],
+            program_id,
+        );
+        if replay_pda != *replay_info.key {
+            return Err(NeuralPathwayError::InvalidVaa.into());
+        }
+        if !replay_info.data_is_empty() {
+            return Err(NeuralPathwayError::VaaAlreadyExecuted.into());
+        }
+
+        create_pda_account(
+            payer_info,
+            replay_info,
+            &[
+                b"vaa",
+                &vaa.body.emitter_address,
+                &vaa.body.sequence.to_le_bytes(),
+                &[replay_bump],
+            ],
+            ProcessedVaa::LEN,
+            program_id,
+            system_program_info,
+        )?;
+        ProcessedVaa {
+            sequence: vaa.body.sequence,
+        }
+        .serialize(&mut &mut replay_info.data.borrow_mut()[..])?;
+
+        let (pathway_pda, pathway_bump) = Pubkey::find_program_address(
+            &[
+                b"pathway",
+                source_agent.as_ref(),
+                target_agent.as_ref(),
+            ],
+            program_id,
+        );
+        if pathway_pda != *pathway_info.key {
+            return Err(NeuralPathwayError::InvalidAgent.into());
+        }
+
+        if pathway_info.data_is_empty() {
+            create_pda_account(
+                payer_info,
+                pathway_info,
+                &[
+                    b"pathway",
+                    source_agent.as_ref(),
+                    target_agent.as_ref(),
+                    &[pathway_bump],
+                ],
+                NeuralPathway::LEN,
+                program_id,
+                system_program_info,
+            )?;
+            let pathway = NeuralPathway::new(source_agent, target_agent, clock.unix_timestamp);
+            pathway.serialize(&mut &mut pathway_info.data.borrow_mut()[..])?;
+        } else {
+            let mut pathway = NeuralPathway::try_from_slice(&pathway_info.data.borrow())?;
+            pathway.reinforce(true, clock.unix_timestamp);
+            pathway.serialize(&mut &mut pathway_info.data.borrow_mut()[..])?;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_rotate_guardian_set(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        index: u32,
+        guardians: Vec<[u8; 20]>,
+        expiration_time: i64,
+        allowed_emitter_chain: u16,
+        allowed_emitter_address: [u8; 32],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let admin_info = next_account_info(account_info_iter)?;
+        let new_set_info = next_account_info(account_info_iter)?;
+
+        if !admin_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let (new_set_pda, bump_seed) = Pubkey::find_program_address(
+            &[b"guardian_set", &index.to_le_bytes()],
+            program_id,
+        );
+        if new_set_pda != *new_set_info.key {
+            return Err(NeuralPathwayError::GuardianSetMismatch.into());
+        }
+        if !new_set_info.data_is_empty() {
+            return Err(NeuralPathwayError::PathwayAlreadyExists.into());
+        }
+
+        if index > 0 {
+            let previous_info = next_account_info(account_info_iter)?;
+            let (previous_pda, _) = Pubkey::find_program_address(
+                &[b"guardian_set", &(index - 1).to_le_bytes()],
+                program_id,
+            );
+            if previous_pda != *previous_info.key {
+                return Err(NeuralPathwayError::GuardianSetMismatch.into());
+            }
+            if previous_info.owner != program_id {
+                return Err(ProgramError::IllegalOwner);
+            }
+            let previous_set = GuardianSet::try_from_slice(&previous_info.data.borrow())?;
+            if previous_set.admin != *admin_info.key {
+                return Err(NeuralPathwayError::UnauthorizedGuardianSetAdmin.into());
+            }
+        }
+
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        let space = GuardianSet::len_for(guardians.len());
+        create_pda_account(
+            admin_info,
+            new_set_info,
+            &[b"guardian_set", &index.to_le_bytes(), &[bump_seed]],
+            space,
+            program_id,
+            system_program_info,
+        )?;
+
+        let guardian_set = GuardianSet {
+            admin: *admin_info.key,
+            index,
+            guardians,
+            expiration_time,
+            allowed_emitter_chain,
+            allowed_emitter_address,
+        };
+        guardian_set.serialize(&mut &mut new_set_info.data.borrow_mut()[..])?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // This harness has no BPF runtime or sysvar stubs, so Rent::get() always
+    // fails with UnsupportedSysvar once it's reached. That's useful rather
+    // than limiting: every handler validates and derives its accounts before
+    // touching `Rent`, so a test hitting exactly `UnsupportedSysvar` proves
+    // every account-order, PDA-derivation, and ownership check ahead of it
+    // passed, without needing a real runtime to exercise account creation.
+
+    fn system_program_account<'a>(
+        key: &'a Pubkey,
+        lamports: &'a mut u64,
+        data: &'a mut [u8],
+    ) -> AccountInfo<'a> {
+        AccountInfo::new(key, false, false, lamports, data, key, true, 0)
+    }
+
+    #[test]
+    fn create_pathway_requires_source_agent_signer() {
+        let program_id = Pubkey::new_unique();
+        let source_agent = Pubkey::new_unique();
+        let target = Pubkey::new_unique();
+        let system_program_id = solana_program::system_program::id();
+
+        let mut source_lamports = 0u64;
+        let mut source_data = vec![];
+        let source_info = AccountInfo::new(
+            &source_agent, false, true, &mut source_lamports, &mut source_data,
+            &system_program_id, false, 0,
+        );
+
+        let (pathway_pda, _) = Pubkey::find_program_address(
+            &[b"pathway", source_agent.as_ref(), target.as_ref()],
+            &program_id,
+        );
+        let mut pathway_lamports = 0u64;
+        let mut pathway_data = vec![];
+        let pathway_info = AccountInfo::new(
+            &pathway_pda, false, true, &mut pathway_lamports, &mut pathway_data,
+            &system_program_id, false, 0,
+        );
+
+        let mut sys_lamports = 1u64;
+        let mut sys_data = vec![];
+        let sys_info = system_program_account(&system_program_id, &mut sys_lamports, &mut sys_data);
+
+        let accounts = [source_info, pathway_info, sys_info];
+        let result = Processor::process_create_pathway(&program_id, &accounts, target);
+        assert_eq!(result, Err(ProgramError::MissingRequiredSignature));
+    }
+
+    #[test]
+    fn create_pathway_rejects_account_that_is_not_the_derived_pda() {
+        let program_id = Pubkey::new_unique();
+        let source_agent = Pubkey::new_unique();
+        let target = Pubkey::new_unique();
+        let system_program_id = solana_program::system_program::id();
+
+        let mut source_lamports = 0u64;
+        let mut source_data = vec![];
+        let source_info = AccountInfo::new(
+            &source_agent, true, true, &mut source_lamports, &mut source_data,
+            &system_program_id, false, 0,
+        );
+
+        let wrong_pathway_key = Pubkey::new_unique();
+        let mut pathway_lamports = 0u64;
+        let mut pathway_data = vec![];
+        let pathway_info = AccountInfo::new(
+            &wrong_pathway_key, false, true, &mut pathway_lamports, &mut pathway_data,
+            &system_program_id, false, 0,
+        );
+
+        let mut sys_lamports = 1u64;
+        let mut sys_data = vec![];
+        let sys_info = system_program_account(&system_program_id, &mut sys_lamports, &mut sys_data);
+
+        let accounts = [source_info, pathway_info, sys_info];
+        let result = Processor::process_create_pathway(&program_id, &accounts, target);
+        assert_eq!(result, Err(NeuralPathwayError::InvalidAgent.into()));
+    }
+
+    #[test]
+    fn post_message_rejects_pathway_not_owned_by_program() {
+        let program_id = Pubkey::new_unique();
+        let author = Pubkey::new_unique();
+        let system_program_id = solana_program::system_program::id();
+
+        let mut author_lamports = 0u64;
+        let mut author_data = vec![];
+        let author_info = AccountInfo::new(
+            &author, true, true, &mut author_lamports, &mut author_data,
+            &system_program_id, false, 0,
+        );
+
+        let pathway_key = Pubkey::new_unique();
+        let mut pathway_lamports = 0u64;
+        let mut pathway_data = vec![0u8; NeuralPathway::LEN];
+        let pathway_info = AccountInfo::new(
+            &pathway_key, false, true, &mut pathway_lamports, &mut pathway_data,
+            &system_program_id, false, 0,
+        );
+
+        let message_key = Pubkey::new_unique();
+        let mut message_lamports = 0u64;
+        let mut message_data = vec![];
+        let message_info = AccountInfo::new(
+            &message_key, false, true, &mut message_lamports, &mut message_data,
+            &system_program_id, false, 0,
+        );
+
+        let mut sys_lamports = 1u64;
+        let mut sys_data = vec![];
+        let sys_info = system_program_account(&system_program_id, &mut sys_lamports, &mut sys_data);
+
+        let accounts = [author_info, pathway_info, message_info, sys_info];
+        let result = Processor::process_post_message(&program_id, &accounts, None, "hi".to_string());
+        assert_eq!(result, Err(ProgramError::IllegalOwner));
+    }
+
+    #[test]
+    fn post_message_rejects_reply_to_a_parent_not_owned_by_program() {
+        let program_id = Pubkey::new_unique();
+        let author = Pubkey::new_unique();
+        let target = Pubkey::new_unique();
+        let system_program_id = solana_program::system_program::id();
+
+        let mut author_lamports = 0u64;
+        let mut author_data = vec![];
+        let author_info = AccountInfo::new(
+            &author, true, true, &mut author_lamports, &mut author_data,
+            &system_program_id, false, 0,
+        );
+
+        let pathway_key = Pubkey::new_unique();
+        let pathway = NeuralPathway::new(author, target, 0);
+        let mut pathway_lamports = 0u64;
+        let mut pathway_data = pathway.try_to_vec().unwrap();
+        let pathway_info = AccountInfo::new(
+            &pathway_key, false, true, &mut pathway_lamports, &mut pathway_data,
+            &program_id, false, 0,
+        );
+
+        let message_key = Pubkey::new_unique();
+        let mut message_lamports = 0u64;
+        let mut message_data = vec![];
+        let message_info = AccountInfo::new(
+            &message_key, false, true, &mut message_lamports, &mut message_data,
+            &system_program_id, false, 0,
+        );
+
+        let mut sys_lamports = 1u64;
+        let mut sys_data = vec![];
+        let sys_info = system_program_account(&system_program_id, &mut sys_lamports, &mut sys_data);
+
+        let parent_key = Pubkey::new_unique();
+        let mut parent_lamports = 0u64;
+        let mut parent_data = vec![];
+        let parent_info = AccountInfo::new(
+            &parent_key, false, true, &mut parent_lamports, &mut parent_data,
+            &system_program_id, false, 0,
+        );
+
+        let accounts = [author_info, pathway_info, message_info, sys_info, parent_info];
+        let result = Processor::process_post_message(
+            &program_id, &accounts, Some(parent_key), "reply".to_string(),
+        );
+        assert_eq!(result, Err(NeuralPathwayError::InvalidReplyTarget.into()));
+    }
+
+    /// Regression test for the account-order bug: `RotateGuardianSet`'s own
+    /// doc comment orders accounts as
+    /// `(admin, new_set, [previous], system_program)`, but the handler used
+    /// to read `system_program` right after `new_set`, so a client building
+    /// the instruction per the docs for `index > 0` had its `previous`
+    /// account silently bound to what the handler treated as
+    /// `system_program_info`, and its real `system_program` account bound to
+    /// `previous_info` -- failing the PDA-match check against it.
+    ///
+    /// With accounts supplied in the documented order, the handler should
+    /// get past every admin/PDA/ownership check and reach `Rent::get()`
+    /// (which this harness can't satisfy, so it's the deepest point a
+    /// bare unit test can observe -- see the module-level comment above).
+    #[test]
+    fn rotate_guardian_set_reads_previous_before_system_program_for_index_gt_zero() {
+        let program_id = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+        let system_program_id = solana_program::system_program::id();
+
+        let (new_set_pda, _) =
+            Pubkey::find_program_address(&[b"guardian_set", &1u32.to_le_bytes()], &program_id);
+        let (previous_pda, _) =
+            Pubkey::find_program_address(&[b"guardian_set", &0u32.to_le_bytes()], &program_id);
+
+        let mut admin_lamports = 0u64;
+        let mut admin_data = vec![];
+        let admin_info = AccountInfo::new(
+            &admin, true, true, &mut admin_lamports, &mut admin_data,
+            &system_program_id, false, 0,
+        );
+
+        let mut new_set_lamports = 0u64;
+        let mut new_set_data = vec![];
+        let new_set_info = AccountInfo::new(
+            &new_set_pda, false, true, &mut new_set_lamports, &mut new_set_data,
+            &system_program_id, false, 0,
+        );
+
+        let previous_set = GuardianSet {
+            admin, index: 0, guardians: vec![], expiration_time: 0,
+            allowed_emitter_chain: 0, allowed_emitter_address: [0u8; 32],
+        };
+        let mut previous_lamports = 0u64;
+        let mut previous_data = previous_set.try_to_vec().unwrap();
+        let previous_info = AccountInfo::new(
+            &previous_pda, false, false, &mut previous_lamports, &mut previous_data,
+            &program_id, false, 0,
+        );
+
+        let mut sys_lamports = 1u64;
+        let mut sys_data = vec![];
+        let sys_info = system_program_account(&system_program_id, &mut sys_lamports, &mut sys_data);
+
+        // Exactly the order documented on `RotateGuardianSet`.
+        let accounts = [admin_info, new_set_info, previous_info, sys_info];
+        let result = Processor::process_rotate_guardian_set(
+            &program_id, &accounts, 1, vec![], 0, 0, [0u8; 32],
+        );
+        assert_eq!(result, Err(ProgramError::UnsupportedSysvar));
+    }
+}
@@ -1,6 +1,13 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+/// Seconds of inactivity that correspond to one unit of strength decay.
+pub const HALF_LIFE: i64 = 3600;
+/// Upper bound on the ratio-scaled part of a single reinforcement event's
+/// reward/penalty; together with its fixed base of 1, one event moves
+/// strength by at most `1 + MAX_BOOST`.
+pub const MAX_BOOST: u8 = 20;
+
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct NeuralPathway {
     pub source_agent: Pubkey,
@@ -10,15 +17,13 @@ pub struct NeuralPathway {
     pub last_used: i64,
     pub success_count: u64,
     pub failure_count: u64,
+    pub message_count: u64,
 }
 
 impl NeuralPathway {
-    pub fn new(source_agent: Pubkey, target_agent: Pubkey) -> Self {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs() as i64;
+    pub const LEN: usize = 32 + 32 + 1 + 8 + 8 + 8 + 8 + 8;
 
+    pub fn new(source_agent: Pubkey, target_agent: Pubkey, now: i64) -> Self {
         Self {
             source_agent,
             target_agent,
@@ -27,6 +32,212 @@ impl NeuralPathway {
             last_used: now,
             success_count: 0,
             failure_count: 0,
+            message_count: 0,
         }
     }
-} 
\ No newline at end of file
+
+    /// Applies temporal decay for the time elapsed since `last_used`, without
+    /// touching `last_used` itself (callers update it once they're done
+    /// mutating the pathway for this instruction).
+    fn decay(&mut self, now: i64) {
+        let elapsed = now.saturating_sub(self.last_used).max(0) as u64;
+        let decay_units = (elapsed / HALF_LIFE as u64).min(u8::MAX as u64) as u8;
+        self.strength = self.strength.saturating_sub(decay_units).max(1);
+    }
+
+    /// Applies decay only, e.g. for a standalone `DecayStrength` instruction.
+    pub fn decay_strength(&mut self, now: i64) {
+        self.decay(now);
+        self.last_used = now;
+    }
+
+    /// Applies decay, then Hebbian reinforcement scaled by the recent
+    /// success/failure ratio, and clamps strength into `1..=u8::MAX`.
+    pub fn reinforce(&mut self, success: bool, now: i64) {
+        self.decay(now);
+
+        if success {
+            self.success_count = self.success_count.saturating_add(1);
+        } else {
+            self.failure_count = self.failure_count.saturating_add(1);
+        }
+
+        let total = self.success_count + self.failure_count;
+        let ratio = if success {
+            self.success_count as f64 / total as f64
+        } else {
+            self.failure_count as f64 / total as f64
+        };
+        let delta = 1u8.saturating_add((ratio * MAX_BOOST as f64) as u8);
+
+        self.strength = if success {
+            self.strength.saturating_add(delta)
+        } else {
+            self.strength.saturating_sub(delta).max(1)
+        };
+        self.last_used = now;
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct PathwayMessage {
+    pub pathway_id: Pubkey,
+    pub author: Pubkey,
+    pub posted_at: i64,
+    pub reply_to: Option<Pubkey>,
+    pub body: String,
+}
+
+impl PathwayMessage {
+    /// Size in bytes of the borsh encoding for a message with the given
+    /// `reply_to` and a body of `body_len` bytes, used to size the account
+    /// at creation time. Must match exactly what gets serialized, or
+    /// `try_from_slice` on the stored account will fail with "Not all bytes
+    /// read" (a `None` reply_to borsh-encodes as a single `0` byte, not the
+    /// 33 bytes a `Some` would take).
+    pub fn len_for(reply_to: Option<&Pubkey>, body_len: usize) -> usize {
+        let reply_to_len = if reply_to.is_some() { 1 + 32 } else { 1 };
+        32 + 32 + 8 + reply_to_len + 4 + body_len
+    }
+}
+
+/// A Wormhole guardian set, mirrored on-chain so VAAs can be verified
+/// against the quorum of guardians that signed them.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct GuardianSet {
+    /// Authority allowed to rotate in the next guardian set. Carried over
+    /// from set to set by `RotateGuardianSet` unless a later request adds
+    /// admin hand-off.
+    pub admin: Pubkey,
+    pub index: u32,
+    pub guardians: Vec<[u8; 20]>,
+    /// Unix timestamp at which this guardian set stops being accepted; a
+    /// superseded set's keys must expire rather than remain valid forever.
+    pub expiration_time: i64,
+    pub allowed_emitter_chain: u16,
+    pub allowed_emitter_address: [u8; 32],
+}
+
+impl GuardianSet {
+    /// Size in bytes of the borsh encoding for a set with `num_guardians`
+    /// guardian addresses, used to size the account at creation time.
+    pub fn len_for(num_guardians: usize) -> usize {
+        32 + 4 + (4 + num_guardians * 20) + 8 + 2 + 32
+    }
+}
+
+/// Replay guard: existence of this PDA for a given (emitter, sequence) means
+/// the VAA has already been mirrored locally.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ProcessedVaa {
+    pub sequence: u64,
+}
+
+impl ProcessedVaa {
+    pub const LEN: usize = 8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pathway() -> NeuralPathway {
+        NeuralPathway::new(Pubkey::new_unique(), Pubkey::new_unique(), 1_000)
+    }
+
+    #[test]
+    fn decay_strength_is_a_no_op_with_no_elapsed_time() {
+        let mut pathway = pathway();
+        pathway.strength = 10;
+        pathway.decay_strength(pathway.last_used);
+        assert_eq!(pathway.strength, 10);
+    }
+
+    #[test]
+    fn decay_strength_removes_one_unit_per_half_life() {
+        let mut pathway = pathway();
+        pathway.strength = 10;
+        pathway.decay_strength(pathway.last_used + 3 * HALF_LIFE);
+        assert_eq!(pathway.strength, 7);
+        assert_eq!(pathway.last_used, 1_000 + 3 * HALF_LIFE);
+    }
+
+    #[test]
+    fn decay_strength_never_drops_below_one() {
+        let mut pathway = pathway();
+        pathway.strength = 3;
+        pathway.decay_strength(pathway.last_used + 100 * HALF_LIFE);
+        assert_eq!(pathway.strength, 1);
+    }
+
+    #[test]
+    fn reinforce_success_increases_strength_and_success_count() {
+        let mut pathway = pathway();
+        pathway.strength = 1;
+        pathway.reinforce(true, pathway.last_used);
+        assert_eq!(pathway.success_count, 1);
+        assert_eq!(pathway.failure_count, 0);
+        assert!(pathway.strength > 1);
+    }
+
+    #[test]
+    fn reinforce_failure_decreases_strength_but_floors_at_one() {
+        let mut pathway = pathway();
+        pathway.strength = 1;
+        pathway.reinforce(false, pathway.last_used);
+        assert_eq!(pathway.failure_count, 1);
+        assert_eq!(pathway.strength, 1);
+    }
+
+    #[test]
+    fn reinforce_applies_decay_before_reinforcing() {
+        let mut pathway = pathway();
+        pathway.strength = 10;
+        pathway.reinforce(false, pathway.last_used + 3 * HALF_LIFE);
+        // Decay first removes 3, then a first-ever failure (ratio 1.0)
+        // subtracts 1 + MAX_BOOST.
+        assert_eq!(pathway.strength, 1);
+    }
+
+    #[test]
+    fn pathway_message_len_for_matches_actual_serialized_size() {
+        let author = Pubkey::new_unique();
+        let without_reply = PathwayMessage {
+            pathway_id: Pubkey::new_unique(),
+            author,
+            posted_at: 1,
+            reply_to: None,
+            body: "hi".to_string(),
+        };
+        assert_eq!(
+            without_reply.try_to_vec().unwrap().len(),
+            PathwayMessage::len_for(None, "hi".len())
+        );
+
+        let reply_to = Pubkey::new_unique();
+        let with_reply = PathwayMessage {
+            reply_to: Some(reply_to),
+            ..without_reply
+        };
+        assert_eq!(
+            with_reply.try_to_vec().unwrap().len(),
+            PathwayMessage::len_for(Some(&reply_to), "hi".len())
+        );
+    }
+
+    #[test]
+    fn guardian_set_len_for_matches_actual_serialized_size() {
+        let guardian_set = GuardianSet {
+            admin: Pubkey::new_unique(),
+            index: 0,
+            guardians: vec![[7u8; 20]; 3],
+            expiration_time: 0,
+            allowed_emitter_chain: 2,
+            allowed_emitter_address: [0u8; 32],
+        };
+        assert_eq!(
+            guardian_set.try_to_vec().unwrap().len(),
+            GuardianSet::len_for(3)
+        );
+    }
+}
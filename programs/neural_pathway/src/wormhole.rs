@@ -0,0 +1,271 @@
+//! Minimal Wormhole VAA (Verified Action Approval) parsing and guardian
+//! signature verification, scoped to what `MirrorPathwayViaVaa` needs.
+
+use std::collections::BTreeSet;
+
+use solana_program::keccak;
+use solana_program::secp256k1_recover::secp256k1_recover;
+
+use crate::error::NeuralPathwayError;
+
+pub struct GuardianSignature {
+    pub guardian_index: u8,
+    pub recovery_id: u8,
+    pub signature: [u8; 64],
+}
+
+pub struct VaaBody {
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub payload: Vec<u8>,
+}
+
+pub struct Vaa {
+    pub guardian_set_index: u32,
+    pub signatures: Vec<GuardianSignature>,
+    pub body: VaaBody,
+    body_bytes: Vec<u8>,
+}
+
+impl Vaa {
+    /// Parses the Wormhole wire format:
+    /// `version(1) | guardian_set_index(4) | signature_count(1) |
+    ///  [guardian_index(1) | signature(65)]* |
+    ///  timestamp(4) | nonce(4) | emitter_chain(2) | emitter_address(32) |
+    ///  sequence(8) | consistency_level(1) | payload(..)`
+    pub fn parse(data: &[u8]) -> Result<Self, NeuralPathwayError> {
+        let mut cursor = Cursor::new(data);
+
+        let _version = cursor.take_u8()?;
+        let guardian_set_index = cursor.take_u32()?;
+        let signature_count = cursor.take_u8()?;
+
+        let mut signatures = Vec::with_capacity(signature_count as usize);
+        for _ in 0..signature_count {
+            let guardian_index = cursor.take_u8()?;
+            let sig_bytes = cursor.take_bytes(65)?;
+            let mut signature = [0u8; 64];
+            signature.copy_from_slice(&sig_bytes[..64]);
+            let recovery_id = sig_bytes[64];
+            signatures.push(GuardianSignature {
+                guardian_index,
+                recovery_id,
+                signature,
+            });
+        }
+
+        let body_bytes = cursor.remaining().to_vec();
+        let mut body_cursor = Cursor::new(&body_bytes);
+        let _timestamp = body_cursor.take_u32()?;
+        let _nonce = body_cursor.take_u32()?;
+        let emitter_chain = body_cursor.take_u16()?;
+        let emitter_address_bytes = body_cursor.take_bytes(32)?;
+        let mut emitter_address = [0u8; 32];
+        emitter_address.copy_from_slice(emitter_address_bytes);
+        let sequence = body_cursor.take_u64()?;
+        let _consistency_level = body_cursor.take_u8()?;
+        let payload = body_cursor.remaining().to_vec();
+
+        Ok(Self {
+            guardian_set_index,
+            signatures,
+            body: VaaBody {
+                emitter_chain,
+                emitter_address,
+                sequence,
+                payload,
+            },
+            body_bytes,
+        })
+    }
+
+    /// Wormhole guardians sign the double-keccak256 hash of the VAA body.
+    fn digest(&self) -> [u8; 32] {
+        let inner = keccak::hash(&self.body_bytes);
+        keccak::hash(inner.as_ref()).to_bytes()
+    }
+
+    /// Verifies that at least a 2/3+1 quorum of `guardians` signed this VAA,
+    /// with no guardian signing twice.
+    pub fn verify_quorum(&self, guardians: &[[u8; 20]]) -> Result<(), NeuralPathwayError> {
+        let required = guardians.len() * 2 / 3 + 1;
+        if self.signatures.len() < required {
+            return Err(NeuralPathwayError::InsufficientSignatures);
+        }
+
+        let digest = self.digest();
+        let mut seen = BTreeSet::new();
+        for sig in &self.signatures {
+            if !seen.insert(sig.guardian_index) {
+                return Err(NeuralPathwayError::InvalidVaa);
+            }
+            let guardian = guardians
+                .get(sig.guardian_index as usize)
+                .ok_or(NeuralPathwayError::InvalidVaa)?;
+
+            let recovered = secp256k1_recover(&digest, sig.recovery_id, &sig.signature)
+                .map_err(|_| NeuralPathwayError::InvalidVaa)?;
+            let address = &keccak::hash(&recovered.to_bytes()).to_bytes()[12..32];
+            if address != guardian {
+                return Err(NeuralPathwayError::InvalidVaa);
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn take_bytes(&mut self, len: usize) -> Result<&'a [u8], NeuralPathwayError> {
+        let end = self.pos.checked_add(len).ok_or(NeuralPathwayError::InvalidVaa)?;
+        let slice = self.data.get(self.pos..end).ok_or(NeuralPathwayError::InvalidVaa)?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn take_u8(&mut self) -> Result<u8, NeuralPathwayError> {
+        Ok(self.take_bytes(1)?[0])
+    }
+
+    fn take_u16(&mut self) -> Result<u16, NeuralPathwayError> {
+        let bytes = self.take_bytes(2)?;
+        Ok(u16::from_be_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn take_u32(&mut self) -> Result<u32, NeuralPathwayError> {
+        let bytes = self.take_bytes(4)?;
+        Ok(u32::from_be_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn take_u64(&mut self) -> Result<u64, NeuralPathwayError> {
+        let bytes = self.take_bytes(8)?;
+        Ok(u64::from_be_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn remaining(&self) -> &'a [u8] {
+        &self.data[self.pos..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds raw Wormhole VAA bytes with `signatures` as
+    /// `(guardian_index, signature, recovery_id)` triples, so tests can
+    /// exercise `Vaa::parse`/`verify_quorum` with placeholder signatures,
+    /// or with real ones produced through the `libsecp256k1` dev-dependency
+    /// where a signature has to verify.
+    fn build_vaa(
+        guardian_set_index: u32,
+        signatures: &[(u8, [u8; 64], u8)],
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+        sequence: u64,
+        payload: &[u8],
+    ) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.push(1u8); // version
+        out.extend_from_slice(&guardian_set_index.to_be_bytes());
+        out.push(signatures.len() as u8);
+        for (guardian_index, signature, recovery_id) in signatures {
+            out.push(*guardian_index);
+            out.extend_from_slice(signature);
+            out.push(*recovery_id);
+        }
+        out.extend_from_slice(&0u32.to_be_bytes()); // timestamp
+        out.extend_from_slice(&0u32.to_be_bytes()); // nonce
+        out.extend_from_slice(&emitter_chain.to_be_bytes());
+        out.extend_from_slice(&emitter_address);
+        out.extend_from_slice(&sequence.to_be_bytes());
+        out.push(0u8); // consistency_level
+        out.extend_from_slice(payload);
+        out
+    }
+
+    #[test]
+    fn parse_reads_header_and_body_fields() {
+        // A 64-byte payload: source agent key, then target agent key.
+        let payload = [[1u8; 32], [2u8; 32]].concat();
+        let emitter_address = [9u8; 32];
+        let bytes = build_vaa(7, &[(0, [1u8; 64], 27)], 2, emitter_address, 42, &payload);
+
+        let vaa = Vaa::parse(&bytes).unwrap();
+        assert_eq!(vaa.guardian_set_index, 7);
+        assert_eq!(vaa.signatures.len(), 1);
+        assert_eq!(vaa.signatures[0].guardian_index, 0);
+        assert_eq!(vaa.signatures[0].recovery_id, 27);
+        assert_eq!(vaa.body.emitter_chain, 2);
+        assert_eq!(vaa.body.emitter_address, emitter_address);
+        assert_eq!(vaa.body.sequence, 42);
+        assert_eq!(vaa.body.payload, payload);
+    }
+
+    #[test]
+    fn parse_rejects_truncated_data() {
+        let bytes = build_vaa(1, &[(0, [0u8; 64], 0)], 1, [0u8; 32], 1, b"x");
+        let truncated = &bytes[..bytes.len() - 5];
+        assert!(matches!(
+            Vaa::parse(truncated),
+            Err(NeuralPathwayError::InvalidVaa)
+        ));
+    }
+
+    #[test]
+    fn verify_quorum_rejects_too_few_signatures() {
+        let bytes = build_vaa(1, &[], 1, [0u8; 32], 1, b"payload");
+        let vaa = Vaa::parse(&bytes).unwrap();
+        // 3 guardians requires a 3*2/3 + 1 = 3 signature quorum.
+        let guardians = vec![[1u8; 20], [2u8; 20], [3u8; 20]];
+        assert!(matches!(
+            vaa.verify_quorum(&guardians),
+            Err(NeuralPathwayError::InsufficientSignatures)
+        ));
+    }
+
+    #[test]
+    fn verify_quorum_rejects_same_guardian_signing_twice() {
+        // Guardian 0 signs the body for real, so its first signature
+        // verifies and only the reused guardian_index can reject the VAA.
+        let secret = libsecp256k1::SecretKey::parse(&[7u8; 32]).unwrap();
+        let public = libsecp256k1::PublicKey::from_secret_key(&secret);
+        let mut guardian = [0u8; 20];
+        guardian.copy_from_slice(&keccak::hash(&public.serialize()[1..]).to_bytes()[12..]);
+        let digest = Vaa::parse(&build_vaa(1, &[], 1, [0u8; 32], 1, b"payload"))
+            .unwrap()
+            .digest();
+        let (signature, recovery_id) =
+            libsecp256k1::sign(&libsecp256k1::Message::parse(&digest), &secret);
+        let signed = (0, signature.serialize(), recovery_id.serialize());
+
+        let bytes = build_vaa(1, &[signed, signed], 1, [0u8; 32], 1, b"payload");
+        let vaa = Vaa::parse(&bytes).unwrap();
+        // A single guardian already satisfies the quorum count, but the
+        // second signature reuses the same guardian_index.
+        let guardians = vec![guardian];
+        assert!(matches!(
+            vaa.verify_quorum(&guardians),
+            Err(NeuralPathwayError::InvalidVaa)
+        ));
+    }
+
+    #[test]
+    fn verify_quorum_rejects_out_of_range_guardian_index() {
+        let bytes = build_vaa(1, &[(5, [0u8; 64], 0)], 1, [0u8; 32], 1, b"payload");
+        let vaa = Vaa::parse(&bytes).unwrap();
+        let guardians = vec![[1u8; 20]];
+        assert!(matches!(
+            vaa.verify_quorum(&guardians),
+            Err(NeuralPathwayError::InvalidVaa)
+        ));
+    }
+}
@@ -0,0 +1,23 @@
+use borsh::BorshDeserialize;
+use solana_program::program_error::ProgramError;
+
+use crate::error::TokenError;
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub enum TokenInstruction {
+    /// Create the metadata account for a mint tied to a neural pathway.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` owner / payer
+    /// 1. `[]` mint
+    /// 2. `[]` pathway
+    /// 3. `[writable]` token metadata PDA, uninitialized
+    /// 4. `[]` system program
+    CreateTokenMetadata { uri: String },
+}
+
+impl TokenInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        Self::try_from_slice(input).map_err(|_| TokenError::InvalidInstruction.into())
+    }
+}
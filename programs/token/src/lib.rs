@@ -1,12 +1,21 @@
+// solana-program 1.18's `entrypoint!` predates this toolchain's stricter
+// `unexpected_cfgs` lint; its internal `custom-heap`/`custom-panic`/`solana`
+// cfgs aren't declared to rustc's check-cfg.
+#![allow(unexpected_cfgs)]
+
 use solana_program::{
     account_info::AccountInfo,
     entrypoint,
     entrypoint::ProgramResult,
-    msg,
     pubkey::Pubkey,
 };
 
 mod state;
+mod error;
+mod instruction;
+mod processor;
+
+use processor::Processor;
 
 entrypoint!(process_instruction);
 
@@ -15,16 +24,11 @@ pub fn process_instruction(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    msg!("AETHERMIND Token Program: {:?}", instruction_data);
-    Ok(())
+    Processor::process(program_id, accounts, instruction_data)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[test]
-    fn test_sanity() {
-        assert!(true);
-    }
-} 
\ No newline at end of file
+    fn test_sanity() {}
+}
@@ -0,0 +1,182 @@
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+use solana_program::sysvar::clock::Clock;
+use solana_program::sysvar::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::TokenError;
+use crate::instruction::TokenInstruction;
+use crate::state::TokenMetadata;
+
+/// Program id of the neural_pathway program, whose `NeuralPathway` accounts
+/// this program reads by raw layout. Must track `neural_pathway::id()`.
+pub const NEURAL_PATHWAY_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("9LFrTQStueCQxJEwxQ5MApFofziRhUHyNkTASAtDns1r");
+
+pub struct Processor;
+
+/// Creates `target_info` as a `space`-byte account owned by `owner`, signed
+/// for by `target_seeds`.
+///
+/// Uses plain `create_account` in the common case, but tolerates
+/// `target_info` having been pre-funded with lamports (and left otherwise
+/// uninitialized) before this instruction ran: anyone can send lamports to a
+/// PDA ahead of its legitimate creation transaction, and `create_account`
+/// refuses to act on a non-zero-lamport account, which would otherwise
+/// permanently block creation. In that case, top the account up to
+/// rent-exemption and bring it under this program's ownership with
+/// Allocate + Assign instead, which only require an empty owner/data, not
+/// zero lamports.
+fn create_pda_account<'a>(
+    payer_info: &AccountInfo<'a>,
+    target_info: &AccountInfo<'a>,
+    target_seeds: &[&[u8]],
+    space: usize,
+    owner: &Pubkey,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    let required_lamports = rent.minimum_balance(space);
+
+    if target_info.lamports() == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                target_info.key,
+                required_lamports,
+                space as u64,
+                owner,
+            ),
+            &[
+                payer_info.clone(),
+                target_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[target_seeds],
+        );
+    }
+
+    let shortfall = required_lamports.saturating_sub(target_info.lamports());
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, target_info.key, shortfall),
+            &[
+                payer_info.clone(),
+                target_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(target_info.key, space as u64),
+        &[target_info.clone(), system_program_info.clone()],
+        &[target_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(target_info.key, owner),
+        &[target_info.clone(), system_program_info.clone()],
+        &[target_seeds],
+    )
+}
+
+impl Processor {
+    pub fn process(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        instruction_data: &[u8],
+    ) -> ProgramResult {
+        let instruction = TokenInstruction::unpack(instruction_data)?;
+
+        match instruction {
+            TokenInstruction::CreateTokenMetadata { uri } => {
+                Self::process_create_token_metadata(program_id, accounts, uri)
+            }
+        }
+    }
+
+    fn process_create_token_metadata(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        uri: String,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let owner_info = next_account_info(account_info_iter)?;
+        let mint_info = next_account_info(account_info_iter)?;
+        let pathway_info = next_account_info(account_info_iter)?;
+        let metadata_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        if !owner_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        Self::check_is_pathway_agent(owner_info, pathway_info)?;
+
+        let (metadata_pda, bump_seed) = Pubkey::find_program_address(
+            &[b"token_metadata", mint_info.key.as_ref()],
+            program_id,
+        );
+        if metadata_pda != *metadata_info.key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if !metadata_info.data_is_empty() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        let space = TokenMetadata::len_for(uri.len());
+        create_pda_account(
+            owner_info,
+            metadata_info,
+            &[b"token_metadata", mint_info.key.as_ref(), &[bump_seed]],
+            space,
+            program_id,
+            system_program_info,
+        )?;
+
+        let clock = Clock::get()?;
+        let metadata = TokenMetadata::new(
+            *pathway_info.key,
+            *mint_info.key,
+            *owner_info.key,
+            uri,
+            clock.unix_timestamp,
+        );
+        metadata.serialize(&mut &mut metadata_info.data.borrow_mut()[..])?;
+
+        Ok(())
+    }
+
+    /// Requires `owner_info` to be one of the two agents of the
+    /// `NeuralPathway` at `pathway_info`, read by raw offset since this
+    /// crate does not depend on the neural_pathway program's types
+    /// (`source_agent` and `target_agent` are its first two `Pubkey` fields).
+    /// Requires `pathway_info` to actually be owned by the neural_pathway
+    /// program, since otherwise an attacker could supply an account they
+    /// control, pre-populated with arbitrary bytes at those offsets.
+    fn check_is_pathway_agent(
+        owner_info: &AccountInfo,
+        pathway_info: &AccountInfo,
+    ) -> ProgramResult {
+        if pathway_info.owner != &NEURAL_PATHWAY_PROGRAM_ID {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        let data = pathway_info.data.borrow();
+        if data.len() < 64 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let source_agent =
+            Pubkey::try_from(&data[0..32]).map_err(|_| ProgramError::InvalidAccountData)?;
+        let target_agent =
+            Pubkey::try_from(&data[32..64]).map_err(|_| ProgramError::InvalidAccountData)?;
+
+        if *owner_info.key != source_agent && *owner_info.key != target_agent {
+            return Err(TokenError::InvalidAgent.into());
+        }
+        Ok(())
+    }
+}
@@ -12,12 +12,13 @@ pub struct TokenMetadata {
 }
 
 impl TokenMetadata {
-    pub fn new(pathway_id: Pubkey, mint: Pubkey, owner: Pubkey, uri: String) -> Self {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs() as i64;
+    /// Size in bytes of the borsh encoding for an account with a URI of
+    /// `uri_len` bytes, used to size the account at creation time.
+    pub fn len_for(uri_len: usize) -> usize {
+        32 + 32 + 32 + 8 + 1 + 4 + uri_len
+    }
 
+    pub fn new(pathway_id: Pubkey, mint: Pubkey, owner: Pubkey, uri: String, now: i64) -> Self {
         Self {
             pathway_id,
             mint,
@@ -27,4 +28,4 @@ impl TokenMetadata {
             uri,
         }
     }
-} 
\ No newline at end of file
+}